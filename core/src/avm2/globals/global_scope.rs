@@ -31,7 +31,17 @@ pub fn create_class<'gc>(
     let class = Class::custom_new(
         QName::new(activation.avm2().namespaces.public_all(), istr!("global")),
         Some(activation.avm2().class_defs().object),
-        Method::from_builtin(instance_init, "<global instance initializer>", mc),
+        // `is_variadic: true` matches what `Method::from_builtin` always
+        // used. The arity enforcement that reads `Method::is_variadic()`
+        // isn't part of this checkout, so flipping this to `false` for a
+        // core object-system constructor can't be verified safe here.
+        Method::from_builtin_and_return_type(
+            instance_init,
+            "<global instance initializer>",
+            None,
+            true,
+            mc,
+        ),
         traits,
         mc,
     );
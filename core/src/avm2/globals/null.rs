@@ -19,7 +19,11 @@ pub fn create_class<'gc>(activation: &mut Activation<'_, 'gc>) -> Class<'gc> {
     let class = Class::custom_new(
         QName::new(activation.avm2().namespaces.public_all(), istr!("null")),
         None,
-        Method::from_builtin(null_init, "", mc),
+        // `is_variadic: true` matches what `Method::from_builtin` always
+        // used. The arity enforcement that reads `Method::is_variadic()`
+        // isn't part of this checkout, so flipping this to `false` for a
+        // core object-system constructor can't be verified safe here.
+        Method::from_builtin_and_return_type(null_init, "", None, true, mc),
         vec![],
         mc,
     );
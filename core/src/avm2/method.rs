@@ -89,6 +89,66 @@ impl<'gc> ParamConfig<'gc> {
     }
 }
 
+/// **WIP, not wired up.** Intended to eventually control when a
+/// [`BytecodeMethod`] gets verified, trading startup time against smoother
+/// playback, but nothing constructs any variant other than the `Lazy`
+/// default, and nothing calls [`BytecodeMethod::eagerly_verify`] from a
+/// `TranslationUnit`'s load path. Until that loader-side walk over each
+/// script's init method and every class trait method exists, every method is
+/// still verified lazily, on its first call, regardless of this enum.
+///
+/// Do not treat this as having fixed the frame hitch caused by lazy
+/// verification; it hasn't.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VerificationPolicy {
+    /// Verify each method the first time it's called.
+    #[default]
+    Lazy,
+
+    /// Verify every script-init and class trait method as soon as its
+    /// `TranslationUnit` finishes loading.
+    EagerOnLoad,
+
+    /// Eagerly verify only methods likely to run early, i.e. script-init
+    /// methods and class constructors, leaving the rest lazy.
+    EagerForEntrypoints,
+}
+
+impl VerificationPolicy {
+    /// Whether a method should be verified up front, before its first call,
+    /// given whether it's a script-init method or class constructor.
+    fn should_verify_eagerly(self, is_entrypoint: bool) -> bool {
+        match self {
+            VerificationPolicy::Lazy => false,
+            VerificationPolicy::EagerOnLoad => true,
+            VerificationPolicy::EagerForEntrypoints => is_entrypoint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod verification_policy_tests {
+    use super::VerificationPolicy;
+
+    #[test]
+    fn lazy_never_verifies_eagerly() {
+        assert!(!VerificationPolicy::Lazy.should_verify_eagerly(true));
+        assert!(!VerificationPolicy::Lazy.should_verify_eagerly(false));
+    }
+
+    #[test]
+    fn eager_on_load_always_verifies_eagerly() {
+        assert!(VerificationPolicy::EagerOnLoad.should_verify_eagerly(true));
+        assert!(VerificationPolicy::EagerOnLoad.should_verify_eagerly(false));
+    }
+
+    #[test]
+    fn eager_for_entrypoints_only_verifies_entrypoints() {
+        assert!(VerificationPolicy::EagerForEntrypoints.should_verify_eagerly(true));
+        assert!(!VerificationPolicy::EagerForEntrypoints.should_verify_eagerly(false));
+    }
+}
+
 /// Represents a reference to an AVM2 method and body.
 #[derive(Collect)]
 #[collect(no_drop)]
@@ -205,19 +265,59 @@ impl<'gc> BytecodeMethod<'gc> {
         }
     }
 
+    /// Verify this method, if it hasn't been verified already.
+    ///
+    /// This is idempotent, so it's safe to call both from an eager
+    /// verification pass (see [`VerificationPolicy`]) and from the normal
+    /// call path; whichever runs first does the work, and the other just
+    /// observes the cached result in `verified_info`.
     #[inline(never)]
     pub fn verify(
         this: Gc<'gc, BytecodeMethod<'gc>>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<(), Error<'gc>> {
-        // TODO: avmplus seems to eaglerly verify some methods
+        if this.verified_info.borrow().is_some() {
+            return Ok(());
+        }
+
+        let verified_info = crate::avm2::verify::verify_method(activation, this)?;
 
         *unlock!(
             Gc::write(activation.gc(), this),
             BytecodeMethod,
             verified_info
         )
-        .borrow_mut() = Some(crate::avm2::verify::verify_method(activation, this)?);
+        .borrow_mut() = Some(verified_info);
+
+        Ok(())
+    }
+
+    /// **WIP, not called from anywhere.** Eagerly verify this method ahead
+    /// of its first call, if `policy` calls for it.
+    ///
+    /// Intended to be invoked by a `TranslationUnit` right after it loads its
+    /// ABC, once for each script-init method and each class trait method it
+    /// defines; `is_entrypoint` should be `true` for script-init methods and
+    /// class constructors, and `false` for everything else. This mirrors
+    /// avmplus, which eagerly verifies some methods instead of waiting for
+    /// their first call and causing a hitch mid-playback.
+    ///
+    /// That `TranslationUnit`-side walk doesn't exist yet, so this function
+    /// has no caller and no method is verified any earlier than before.
+    /// `#[allow(dead_code)]` documents that honestly instead of leaving it
+    /// looking like live, wired-up behavior.
+    #[allow(dead_code)]
+    pub fn eagerly_verify(
+        this: Gc<'gc, BytecodeMethod<'gc>>,
+        is_entrypoint: bool,
+        policy: VerificationPolicy,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<(), Error<'gc>> {
+        let should_verify = policy.should_verify_eagerly(is_entrypoint);
+
+        if should_verify {
+            Self::verify(this, activation)?;
+        }
 
         Ok(())
     }
@@ -359,19 +459,36 @@ impl<'gc> Method<'gc> {
     }
 
     /// Define a builtin with no parameter constraints.
+    ///
+    /// This assumes a `None` return type and a variadic signature, which is
+    /// wrong for most builtin methods and will cause `describeType` to
+    /// report inaccurate metadata for them. Prefer
+    /// [`Method::from_builtin_and_return_type`] when the method's real
+    /// return type and variadic-ness are known.
     pub fn from_builtin(method: NativeMethodImpl, name: &'static str, mc: &Mutation<'gc>) -> Self {
-        Self::Native(Gc::new(
-            mc,
-            NativeMethod {
-                method,
-                name,
-                signature: Vec::new(),
-                resolved_signature: GcCell::new(mc, None),
-                // FIXME - take in the real return type. This is needed for 'describeType'
-                return_type: None,
-                is_variadic: true,
-            },
-        ))
+        Self::from_builtin_and_return_type(method, name, None, true, mc)
+    }
+
+    /// Define a builtin with an explicit return type and variadic-ness,
+    /// instead of the `None`/`true` that [`Method::from_builtin`] always
+    /// uses.
+    ///
+    /// This by itself does not fix `describeType` for any real method: the
+    /// only callers migrated so far (`null.rs`, `global_scope.rs`) pass the
+    /// same `None`/`true` `from_builtin` always used, so their behavior is
+    /// unchanged. Fixing `describeType` requires going through the builtin
+    /// class tables that define methods with a real, non-void return type
+    /// (`Array`, `String`, ...) and passing their actual return type here;
+    /// those tables aren't part of this checkout, so that audit has not been
+    /// done, and this function has no real-world effect yet.
+    pub fn from_builtin_and_return_type(
+        method: NativeMethodImpl,
+        name: &'static str,
+        return_type: Option<Gc<'gc, Multiname<'gc>>>,
+        is_variadic: bool,
+        mc: &Mutation<'gc>,
+    ) -> Self {
+        Self::from_builtin_and_params(method, name, Vec::new(), return_type, is_variadic, mc)
     }
 
     /// Access the bytecode of this method.
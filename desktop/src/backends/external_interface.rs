@@ -1,16 +1,184 @@
+use std::path::Path;
+
+use anyhow::Context;
 use ruffle_core::context::UpdateContext;
 use ruffle_core::external::{ExternalInterfaceProvider, Value as ExternalValue};
-use url::Url;
+use serde::Deserialize;
+
+/// A value that can be written by hand in an `ExternalInterface` config file.
+///
+/// This mirrors [`ExternalValue`], minus the container variants, which aren't
+/// useful to hand-author as a canned response.
+#[derive(Clone, Debug, Deserialize)]
+pub enum ConfiguredValue {
+    Null,
+    Undefined,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl From<ConfiguredValue> for ExternalValue {
+    fn from(value: ConfiguredValue) -> Self {
+        match value {
+            ConfiguredValue::Null => ExternalValue::Null,
+            ConfiguredValue::Undefined => ExternalValue::Undefined,
+            ConfiguredValue::Bool(b) => ExternalValue::Bool(b),
+            ConfiguredValue::Number(n) => ExternalValue::Number(n),
+            ConfiguredValue::String(s) => ExternalValue::String(s),
+        }
+    }
+}
+
+/// On-disk representation of a set of `ExternalInterface` method mappings,
+/// e.g. loaded from an `external_interface.ron` next to the executable.
+#[derive(Deserialize)]
+pub struct ExternalInterfaceConfig {
+    /// Pairs of a method name pattern (see [`DesktopExternalInterfaceProvider::with_value`])
+    /// and the value that should be returned when it's called.
+    ///
+    /// This is a `Vec` rather than a map so that the file's authoring order
+    /// is preserved: later entries take priority over earlier ones, same as
+    /// [`DesktopExternalInterfaceProvider::with_value`] calls. A `HashMap`
+    /// would make that precedence depend on unspecified, run-to-run-unstable
+    /// hash iteration order whenever two patterns overlap.
+    #[serde(default)]
+    pub methods: Vec<(String, ConfiguredValue)>,
+}
+
+/// How a single registered method name pattern should be answered.
+enum MethodHandler {
+    /// Always respond with this fixed value.
+    Value(ExternalValue),
+
+    /// Compute the response with a Rust closure. The closure receives the
+    /// exact method name that was called (useful when the pattern ends in
+    /// `*`) and the arguments it was called with.
+    Closure(Box<dyn Fn(&str, &[ExternalValue]) -> ExternalValue>),
+}
+
+impl MethodHandler {
+    fn call(&self, name: &str, args: &[ExternalValue]) -> ExternalValue {
+        match self {
+            MethodHandler::Value(value) => value.clone(),
+            MethodHandler::Closure(f) => f(name, args),
+        }
+    }
+}
+
+fn log_console(level: tracing::Level, args: &[ExternalValue]) -> ExternalValue {
+    let mut message = String::new();
+    for arg in args {
+        match arg {
+            ExternalValue::String(s) => message.push_str(s),
+            ExternalValue::Number(n) => message.push_str(&n.to_string()),
+            ExternalValue::Bool(b) => message.push_str(&b.to_string()),
+            ExternalValue::Undefined => message.push_str("undefined"),
+            ExternalValue::Null => message.push_str("null"),
+            _ => message.push_str("<unknown>"),
+        }
+        message.push(' ');
+    }
+    message.pop(); // remove trailing space
 
+    match level {
+        tracing::Level::ERROR => tracing::error!("ExternalInterface: console: {message}"),
+        tracing::Level::WARN => tracing::warn!("ExternalInterface: console: {message}"),
+        tracing::Level::DEBUG => tracing::debug!("ExternalInterface: console: {message}"),
+        tracing::Level::TRACE => tracing::trace!("ExternalInterface: console: {message}"),
+        tracing::Level::INFO => tracing::info!("ExternalInterface: console: {message}"),
+    }
+
+    ExternalValue::Undefined
+}
+
+/// An [`ExternalInterfaceProvider`] whose responses to `ExternalInterface.call`
+/// are driven by a user-configurable registry of method name patterns,
+/// instead of a fixed set of baked-in behaviors.
+///
+/// Patterns are matched most recently registered first, and a trailing `*` matches any
+/// suffix (e.g. `"window.navigator.*"` matches `"window.navigator.userAgent"`).
+/// By default, `console.log`/`console.warn`/`console.error` are routed to the
+/// matching `tracing` level; register a handler for the same pattern to
+/// override this.
 pub struct DesktopExternalInterfaceProvider {
-    pub spoof_url: Option<Url>,
+    handlers: Vec<(String, MethodHandler)>,
 }
 
-fn is_location_href(code: &str) -> bool {
-    matches!(
-        code,
-        "document.location.href" | "window.location.href" | "top.location.href"
-    )
+impl Default for DesktopExternalInterfaceProvider {
+    fn default() -> Self {
+        Self {
+            handlers: Vec::new(),
+        }
+        .with_closure("console.log", |_, args| {
+            log_console(tracing::Level::INFO, args)
+        })
+        .with_closure("console.warn", |_, args| {
+            log_console(tracing::Level::WARN, args)
+        })
+        .with_closure("console.error", |_, args| {
+            log_console(tracing::Level::ERROR, args)
+        })
+    }
+}
+
+impl DesktopExternalInterfaceProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load method mappings from a RON config file, in addition to any
+    /// handlers already registered. Like any other `with_value`/`with_closure`
+    /// call made afterwards, these take priority over handlers already
+    /// registered (including the built-in `console.*` routing) for any
+    /// pattern they share, so a config file can override pre-registered
+    /// handlers, not just fill in new ones. Within the file, later entries in
+    /// `methods` take priority over earlier ones.
+    pub fn with_config_file(mut self, path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read ExternalInterface config {path:?}"))?;
+        let config: ExternalInterfaceConfig = ron::from_str(&contents)
+            .with_context(|| format!("Couldn't parse ExternalInterface config {path:?}"))?;
+
+        for (pattern, value) in config.methods {
+            self = self.with_value(pattern, value.into());
+        }
+
+        Ok(self)
+    }
+
+    /// Register a fixed value to return whenever `pattern` is called.
+    pub fn with_value(self, pattern: impl Into<String>, value: ExternalValue) -> Self {
+        self.with_handler(pattern, MethodHandler::Value(value))
+    }
+
+    /// Register a closure to compute the value to return whenever `pattern`
+    /// is called.
+    pub fn with_closure(
+        self,
+        pattern: impl Into<String>,
+        f: impl Fn(&str, &[ExternalValue]) -> ExternalValue + 'static,
+    ) -> Self {
+        self.with_handler(pattern, MethodHandler::Closure(Box::new(f)))
+    }
+
+    fn with_handler(mut self, pattern: impl Into<String>, handler: MethodHandler) -> Self {
+        // Insert at the front so later registrations are checked first,
+        // letting them override earlier ones (including the built-in
+        // console.* handlers registered by `Default::default`).
+        self.handlers.insert(0, (pattern.into(), handler));
+        self
+    }
+
+    fn find_handler(&self, name: &str) -> Option<&MethodHandler> {
+        self.handlers.iter().find_map(|(pattern, handler)| {
+            let is_match = match pattern.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => name == pattern,
+            };
+            is_match.then_some(handler)
+        })
+    }
 }
 
 impl ExternalInterfaceProvider for DesktopExternalInterfaceProvider {
@@ -20,21 +188,18 @@ impl ExternalInterfaceProvider for DesktopExternalInterfaceProvider {
         name: &str,
         args: &[ExternalValue],
     ) -> ExternalValue {
-        if let Some(ref url) = self.spoof_url {
-            // Check for e.g. "window.location.href.toString"
-            if let Some(name) = name.strip_suffix(".toString") {
-                if is_location_href(name) {
-                    return url.to_string().into();
-                }
-            }
+        if let Some(handler) = self.find_handler(name) {
+            return handler.call(name, args);
         }
 
         if name == "eval" {
-            if let Some(ref url) = self.spoof_url {
-                if let [ExternalValue::String(ref code)] = args {
-                    if is_location_href(code) {
-                        return ExternalValue::String(url.to_string());
-                    }
+            // Content commonly calls `eval("window.location.href")` (and
+            // similar) instead of reading the property directly. Let a
+            // pattern registered for the evaluated code itself answer this,
+            // the same way it would a direct property access.
+            if let [ExternalValue::String(code)] = args {
+                if let Some(handler) = self.find_handler(code) {
+                    return handler.call(code, args);
                 }
             }
 
@@ -42,29 +207,6 @@ impl ExternalInterfaceProvider for DesktopExternalInterfaceProvider {
             return ExternalValue::Undefined;
         }
 
-        if name == "console.log" {
-            let mut log = String::new();
-            for arg in args {
-                match arg {
-                    ExternalValue::String(s) => log.push_str(s),
-                    ExternalValue::Number(n) => log.push_str(&n.to_string()),
-                    ExternalValue::Bool(b) => log.push_str(&b.to_string()),
-                    ExternalValue::Undefined => log.push_str("undefined"),
-                    ExternalValue::Null => log.push_str("null"),
-                    _ => log.push_str("<unknown>"),
-                }
-                log.push(' ');
-            }
-            log.pop(); // remove last space
-
-            tracing::info!("ExternalInterface: console.log: {log}");
-            return ExternalValue::Undefined;
-        }
-
-        if name == "window.navigator.userAgent.toString" {
-            return ExternalValue::String("mundo-gaturro-desktop".to_string());
-        }
-
         tracing::warn!("Trying to call unknown ExternalInterface method: {name}");
         ExternalValue::Undefined
     }
@@ -75,3 +217,63 @@ impl ExternalInterfaceProvider for DesktopExternalInterfaceProvider {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn user_handler_overrides_default_console_log() {
+        let called = Arc::new(AtomicBool::new(false));
+        let called_clone = called.clone();
+
+        let provider =
+            DesktopExternalInterfaceProvider::new().with_closure("console.log", move |_, _| {
+                called_clone.store(true, Ordering::SeqCst);
+                ExternalValue::Undefined
+            });
+
+        let handler = provider.find_handler("console.log").unwrap();
+        handler.call("console.log", &[]);
+
+        assert!(called.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn value_handler_answers_its_registered_pattern() {
+        let provider = DesktopExternalInterfaceProvider::new().with_value(
+            "window.location.href",
+            ExternalValue::String("https://example.com/".into()),
+        );
+
+        let handler = provider
+            .find_handler("window.location.href")
+            .expect("pattern should be registered");
+        let response = handler.call("window.location.href", &[]);
+
+        assert!(matches!(response, ExternalValue::String(s) if s == "https://example.com/"));
+    }
+
+    #[test]
+    fn config_file_methods_preserve_authoring_order() {
+        let config: ExternalInterfaceConfig = ron::from_str(
+            r#"(methods: [
+                ("console.*", String("wildcard")),
+                ("console.error", String("exact")),
+            ])"#,
+        )
+        .unwrap();
+
+        let mut provider = DesktopExternalInterfaceProvider::new();
+        for (pattern, value) in config.methods {
+            provider = provider.with_value(pattern, value.into());
+        }
+
+        let handler = provider.find_handler("console.error").unwrap();
+        let response = handler.call("console.error", &[]);
+
+        assert!(matches!(response, ExternalValue::String(s) if s == "exact"));
+    }
+}